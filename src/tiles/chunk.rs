@@ -10,23 +10,307 @@ use crate::{err::TileError, map::TilemapSize};
 
 use super::TilePos;
 
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
 /// Marker relation between chunks and maps
 pub struct Chunk;
 
+/// Number of bits consumed per level of a [`SparseStorage`] trie.
+const NIBBLE_BITS: u32 = 4;
+/// Number of children held by each branch/leaf node of a [`SparseStorage`] trie.
+const NIBBLE_WIDTH: usize = 1 << NIBBLE_BITS;
+/// Number of levels required to address every bit of a `usize` index, four bits at a time.
+const MAX_DEPTH: usize = (usize::BITS / NIBBLE_BITS) as usize;
+
+/// A node of the radix trie backing [`SparseStorage`].
+///
+/// `Branch` nodes appear at every depth but the last and simply narrow down the index one
+/// nibble at a time; `Leaf` nodes appear at `MAX_DEPTH - 1` and hold the actual values.
+#[derive(Debug, Clone)]
+enum TrieNode<T> {
+    Branch(Box<[Option<TrieNode<T>>; NIBBLE_WIDTH]>),
+    Leaf(Box<[Option<T>; NIBBLE_WIDTH]>),
+}
+
+impl<T> TrieNode<T> {
+    fn new_branch() -> Self {
+        TrieNode::Branch(Box::new(std::array::from_fn(|_| None)))
+    }
+
+    fn new_leaf() -> Self {
+        TrieNode::Leaf(Box::new(std::array::from_fn(|_| None)))
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            TrieNode::Branch(children) => children.iter().all(Option::is_none),
+            TrieNode::Leaf(values) => values.iter().all(Option::is_none),
+        }
+    }
+}
+
+/// Extracts the nibble of `index` that a node at `depth` is responsible for.
+fn nibble_at(index: usize, depth: usize) -> usize {
+    let shift = (MAX_DEPTH - 1 - depth) * NIBBLE_BITS as usize;
+    (index >> shift) & (NIBBLE_WIDTH - 1)
+}
+
+/// Reconstructs the `TilePos` a flat tile index refers to within a map of the given `size`.
+///
+/// This is the inverse of `TilePos::to_index`.
+fn pos_from_index(index: usize, size: &TilemapSize) -> TilePos {
+    TilePos {
+        x: (index % size.x as usize) as u32,
+        y: (index / size.x as usize) as u32,
+    }
+}
+
+/// A radix-trie-backed tile store keyed on the flat tile index.
+///
+/// Unlike the dense `Vec<Option<T>>` backing, memory is only allocated for the branches of the
+/// trie that lead to a populated tile, so a `SparseStorage` over a huge map costs roughly
+/// `O(occupied)` rather than `O(size.count())`. Subtrees are collapsed back to `None` on
+/// [`SparseStorage::remove`] once they no longer contain any tiles.
+#[derive(Debug, Clone)]
+struct SparseStorage<T> {
+    root: Option<TrieNode<T>>,
+    len: usize,
+}
+
+impl<T> SparseStorage<T> {
+    fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        for depth in 0..MAX_DEPTH - 1 {
+            let TrieNode::Branch(children) = node else {
+                unreachable!("trie branch expected above MAX_DEPTH - 1")
+            };
+            node = children[nibble_at(index, depth)].as_ref()?;
+        }
+        let TrieNode::Leaf(values) = node else {
+            unreachable!("trie leaf expected at MAX_DEPTH - 1")
+        };
+        values[nibble_at(index, MAX_DEPTH - 1)].as_ref()
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let mut node = self.root.as_mut()?;
+        for depth in 0..MAX_DEPTH - 1 {
+            let TrieNode::Branch(children) = node else {
+                unreachable!("trie branch expected above MAX_DEPTH - 1")
+            };
+            node = children[nibble_at(index, depth)].as_mut()?;
+        }
+        let TrieNode::Leaf(values) = node else {
+            unreachable!("trie leaf expected at MAX_DEPTH - 1")
+        };
+        values[nibble_at(index, MAX_DEPTH - 1)].as_mut()
+    }
+
+    /// Descends to the leaf slot for `index`, lazily allocating branches/leaves along the way,
+    /// and returns a mutable reference to it so callers can both inspect and write the slot
+    /// without a second trie descent.
+    fn slot_mut(&mut self, index: usize) -> &mut Option<T> {
+        let mut node = self.root.get_or_insert_with(TrieNode::new_branch);
+        for depth in 0..MAX_DEPTH - 1 {
+            let TrieNode::Branch(children) = node else {
+                unreachable!("trie branch expected above MAX_DEPTH - 1")
+            };
+            let child = &mut children[nibble_at(index, depth)];
+            node = child.get_or_insert_with(|| {
+                if depth + 1 == MAX_DEPTH - 1 {
+                    TrieNode::new_leaf()
+                } else {
+                    TrieNode::new_branch()
+                }
+            });
+        }
+        let TrieNode::Leaf(values) = node else {
+            unreachable!("trie leaf expected at MAX_DEPTH - 1")
+        };
+        &mut values[nibble_at(index, MAX_DEPTH - 1)]
+    }
+
+    fn set(&mut self, index: usize, value: T) -> Option<T> {
+        let previous = self.slot_mut(index).replace(value);
+        if previous.is_none() {
+            self.len += 1;
+        }
+        previous
+    }
+
+    /// Writes `value` into the (assumed vacant) slot at `index` and returns a mutable reference
+    /// to it, without re-descending the trie to look the slot back up afterwards.
+    fn insert_mut(&mut self, index: usize, value: T) -> &mut T {
+        let slot = self.slot_mut(index);
+        if slot.is_none() {
+            self.len += 1;
+        }
+        slot.insert(value)
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        let removed = Self::remove_at(&mut self.root, index, 0);
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    /// Removes the value at `index` below `node_slot`, collapsing `node_slot` back to `None`
+    /// if doing so leaves it empty.
+    fn remove_at(node_slot: &mut Option<TrieNode<T>>, index: usize, depth: usize) -> Option<T> {
+        let node = node_slot.as_mut()?;
+        let removed = if depth == MAX_DEPTH - 1 {
+            let TrieNode::Leaf(values) = node else {
+                unreachable!("trie leaf expected at MAX_DEPTH - 1")
+            };
+            values[nibble_at(index, depth)].take()
+        } else {
+            let TrieNode::Branch(children) = node else {
+                unreachable!("trie branch expected above MAX_DEPTH - 1")
+            };
+            Self::remove_at(&mut children[nibble_at(index, depth)], index, depth + 1)
+        };
+        if removed.is_some() && node.is_empty() {
+            *node_slot = None;
+        }
+        removed
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (usize, &T)> {
+        let mut out = Vec::with_capacity(self.len);
+        if let Some(root) = &self.root {
+            Self::collect(root, 0, &mut out);
+        }
+        out.into_iter()
+    }
+
+    fn collect<'a>(node: &'a TrieNode<T>, prefix: usize, out: &mut Vec<(usize, &'a T)>) {
+        match node {
+            TrieNode::Branch(children) => {
+                for (nibble, child) in children.iter().enumerate() {
+                    if let Some(child) = child {
+                        Self::collect(child, (prefix << NIBBLE_BITS) | nibble, out);
+                    }
+                }
+            }
+            TrieNode::Leaf(values) => {
+                for (nibble, value) in values.iter().enumerate() {
+                    if let Some(value) = value {
+                        out.push(((prefix << NIBBLE_BITS) | nibble, value));
+                    }
+                }
+            }
+        }
+    }
+
+    fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.iter_entries_mut().map(|(_, value)| value)
+    }
+
+    /// Like [`SparseStorage::iter_mut`], but paired with the flat index each value lives at.
+    /// Entries are yielded in ascending index order, since trie traversal visits nibbles from
+    /// most to least significant.
+    fn iter_entries_mut(&mut self) -> impl Iterator<Item = (usize, &mut T)> {
+        let mut out = Vec::with_capacity(self.len);
+        if let Some(root) = &mut self.root {
+            Self::collect_mut(root, 0, &mut out);
+        }
+        out.into_iter()
+    }
+
+    fn collect_mut<'a>(node: &'a mut TrieNode<T>, prefix: usize, out: &mut Vec<(usize, &'a mut T)>) {
+        match node {
+            TrieNode::Branch(children) => {
+                for (nibble, child) in children.iter_mut().enumerate() {
+                    if let Some(child) = child {
+                        Self::collect_mut(child, (prefix << NIBBLE_BITS) | nibble, out);
+                    }
+                }
+            }
+            TrieNode::Leaf(values) => {
+                for (nibble, value) in values.iter_mut().enumerate() {
+                    if let Some(value) = value {
+                        out.push(((prefix << NIBBLE_BITS) | nibble, value));
+                    }
+                }
+            }
+        }
+    }
+
+    fn drain(&mut self) -> impl Iterator<Item = T> {
+        let mut out = Vec::with_capacity(self.len);
+        if let Some(root) = self.root.take() {
+            Self::collect_owned(root, &mut out);
+        }
+        self.len = 0;
+        out.into_iter()
+    }
+
+    fn collect_owned(node: TrieNode<T>, out: &mut Vec<T>) {
+        match node {
+            TrieNode::Branch(children) => {
+                let children: [Option<TrieNode<T>>; NIBBLE_WIDTH] = *children;
+                for child in children.into_iter().flatten() {
+                    Self::collect_owned(child, out);
+                }
+            }
+            TrieNode::Leaf(values) => {
+                let values: [Option<T>; NIBBLE_WIDTH] = *values;
+                out.extend(values.into_iter().flatten());
+            }
+        }
+    }
+}
+
+/// A one-pass summary of a [`ChunkStorage`]'s occupancy, returned by [`ChunkStorage::report`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkStorageReport {
+    /// Number of tile positions with a stored `T`.
+    pub occupied: usize,
+    /// Number of tile positions without a stored `T`.
+    pub vacant: usize,
+    /// Total number of tile positions, i.e. `occupied + vacant`.
+    pub capacity: usize,
+}
+
+/// The storage backend underlying a [`ChunkStorage`].
+#[derive(Debug, Clone)]
+enum ChunkTiles<T> {
+    /// A flat `Vec<Option<T>>` with one slot per tile, allocated up front.
+    Dense(Vec<Option<T>>),
+    /// A radix trie that only allocates memory for populated regions.
+    Sparse(SparseStorage<T>),
+}
+
 /// Used to store tile entities for fast look up.
 /// Tile entities are stored in a grid. The grid is always filled with None.
+///
+/// That grid is the Dense backend; the Sparse backend (see [`ChunkStorage::sparse`]) keeps no
+/// grid and only stores occupied tiles.
+///
+/// `tiles` is `#[reflect(ignore)]`: `ChunkTiles` holds a `SparseStorage` radix trie for the
+/// Sparse backend, which has no meaningful `Reflect` representation, so neither backend's tile
+/// contents are visible to scene serialization or the inspector, only `size`. Reach for
+/// [`ChunkStorage::iter_pos`] (or `get`/`try_get`) to inspect tiles programmatically instead.
 #[derive(Component, Reflect, Debug, Clone)]
 #[reflect(Component)]
 #[reflect(where T: Reflect)]
 pub struct ChunkStorage<T> {
-    tiles: Vec<Option<T>>,
+    #[reflect(ignore)]
+    tiles: ChunkTiles<T>,
     pub size: TilemapSize,
 }
 
 impl<T> Default for ChunkStorage<T> {
     fn default() -> Self {
         ChunkStorage {
-            tiles: vec![],
+            tiles: ChunkTiles::Dense(vec![]),
             size: TilemapSize { x: 0, y: 0 },
         }
     }
@@ -34,27 +318,66 @@ impl<T> Default for ChunkStorage<T> {
 
 impl<T: MapEntities> MapEntities for ChunkStorage<T> {
     fn map_entities<E: EntityMapper>(&mut self, entity_mapper: &mut E) {
-        for tile in self.tiles.iter_mut().flatten() {
-            tile.map_entities(entity_mapper);
+        match &mut self.tiles {
+            ChunkTiles::Dense(tiles) => {
+                for tile in tiles.iter_mut().flatten() {
+                    tile.map_entities(entity_mapper);
+                }
+            }
+            ChunkTiles::Sparse(sparse) => {
+                for tile in sparse.iter_mut() {
+                    tile.map_entities(entity_mapper);
+                }
+            }
         }
     }
 }
 
 impl<T> ChunkStorage<T> {
-    /// Creates a new tile storage that is empty.
+    /// Creates a new tile storage that is empty, backed by a dense `Vec<Option<T>>` with one
+    /// slot pre-allocated for every tile in `size`.
+    ///
+    /// This is the right choice for maps that are small or densely populated. For maps that are
+    /// huge and mostly empty, use [`ChunkStorage::sparse`] instead to avoid paying for
+    /// `size.count()` slots up front.
     pub fn empty(size: TilemapSize) -> Self {
         let mut tiles = Vec::with_capacity(size.count());
         for _ in 0..size.count() {
             tiles.push(None);
         }
-        Self { tiles, size }
+        Self {
+            tiles: ChunkTiles::Dense(tiles),
+            size,
+        }
+    }
+
+    /// Creates a new tile storage that is empty, backed by a radix trie that only allocates
+    /// memory for populated regions.
+    ///
+    /// Use this for huge or procedurally-streamed maps where `size.count()` would be far too
+    /// large to allocate a dense `Vec` for, even though only a handful of tiles end up set.
+    pub fn sparse(size: TilemapSize) -> Self {
+        Self {
+            tiles: ChunkTiles::Sparse(SparseStorage::new()),
+            size,
+        }
     }
 
     /// Gets a tile for the given tile position, if an is associated with that tile position.
     ///
     /// Panics if the given `tile_pos` doesn't lie within the extents of the underlying tile map.
     pub fn get(&self, tile_pos: &TilePos) -> Option<&T> {
-        self.tiles[tile_pos.to_index(&self.size)].as_ref()
+        let index = tile_pos.to_index(&self.size);
+        match &self.tiles {
+            ChunkTiles::Dense(tiles) => tiles[index].as_ref(),
+            ChunkTiles::Sparse(sparse) => {
+                assert!(
+                    tile_pos.within_map_bounds(&self.size),
+                    "the given tile_pos lies outside the extents of the tile map"
+                );
+                sparse.get(index)
+            }
+        }
     }
 
     /// Gets a tile entity for the given tile position, if:
@@ -64,7 +387,11 @@ impl<T> ChunkStorage<T> {
     /// otherwise it returns `None`.
     pub fn try_get(&self, tile_pos: &TilePos) -> Result<Option<&T>, TileError> {
         if tile_pos.within_map_bounds(&self.size) {
-            Ok(self.tiles[tile_pos.to_index(&self.size)].as_ref())
+            let index = tile_pos.to_index(&self.size);
+            Ok(match &self.tiles {
+                ChunkTiles::Dense(tiles) => tiles[index].as_ref(),
+                ChunkTiles::Sparse(sparse) => sparse.get(index),
+            })
         } else {
             Err(TileError::OutOfBounds {
                 size: self.size,
@@ -77,7 +404,17 @@ impl<T> ChunkStorage<T> {
     ///
     /// Panics if the given `tile_pos` doesn't lie within the extents of the underlying tile map.
     pub fn get_mut(&mut self, tile_pos: &TilePos) -> Option<&mut T> {
-        self.tiles[tile_pos.to_index(&self.size)].as_mut()
+        let index = tile_pos.to_index(&self.size);
+        match &mut self.tiles {
+            ChunkTiles::Dense(tiles) => tiles[index].as_mut(),
+            ChunkTiles::Sparse(sparse) => {
+                assert!(
+                    tile_pos.within_map_bounds(&self.size),
+                    "the given tile_pos lies outside the extents of the tile map"
+                );
+                sparse.get_mut(index)
+            }
+        }
     }
 
     /// Gets a tile entity for the given tile position, if:
@@ -87,7 +424,11 @@ impl<T> ChunkStorage<T> {
     /// otherwise it returns `None`.
     pub fn try_get_mut(&mut self, tile_pos: &TilePos) -> Result<Option<&mut T>, TileError> {
         if tile_pos.within_map_bounds(&self.size) {
-            Ok(self.tiles[tile_pos.to_index(&self.size)].as_mut())
+            let index = tile_pos.to_index(&self.size);
+            Ok(match &mut self.tiles {
+                ChunkTiles::Dense(tiles) => tiles[index].as_mut(),
+                ChunkTiles::Sparse(sparse) => sparse.get_mut(index),
+            })
         } else {
             Err(TileError::OutOfBounds {
                 size: self.size,
@@ -102,7 +443,17 @@ impl<T> ChunkStorage<T> {
     ///
     /// Panics if the given `tile_pos` doesn't lie within the extents of the underlying tile map.
     pub fn set(&mut self, tile_pos: &TilePos, tile: T) -> Option<T> {
-        self.tiles[tile_pos.to_index(&self.size)].replace(tile)
+        let index = tile_pos.to_index(&self.size);
+        match &mut self.tiles {
+            ChunkTiles::Dense(tiles) => tiles[index].replace(tile),
+            ChunkTiles::Sparse(sparse) => {
+                assert!(
+                    tile_pos.within_map_bounds(&self.size),
+                    "the given tile_pos lies outside the extents of the tile map"
+                );
+                sparse.set(index, tile)
+            }
+        }
     }
 
     /// Sets a tile entity for the given tile position, if the tile position lies within the
@@ -111,7 +462,11 @@ impl<T> ChunkStorage<T> {
     /// If there is an entity already at that position, the original will be returned.
     pub fn try_set(&mut self, tile_pos: &TilePos, tile: T) -> Result<Option<T>, TileError> {
         if tile_pos.within_map_bounds(&self.size) {
-            Ok(self.tiles[tile_pos.to_index(&self.size)].replace(tile))
+            let index = tile_pos.to_index(&self.size);
+            Ok(match &mut self.tiles {
+                ChunkTiles::Dense(tiles) => tiles[index].replace(tile),
+                ChunkTiles::Sparse(sparse) => sparse.set(index, tile),
+            })
         } else {
             Err(TileError::OutOfBounds {
                 size: self.size,
@@ -121,13 +476,94 @@ impl<T> ChunkStorage<T> {
     }
 
     /// Returns an iterator with all of the positions in the grid.
-    pub fn iter(&self) -> impl Iterator<Item = &Option<T>> {
-        self.tiles.iter()
+    pub fn iter(&self) -> Box<dyn Iterator<Item = Option<&T>> + '_> {
+        match &self.tiles {
+            ChunkTiles::Dense(tiles) => Box::new(tiles.iter().map(Option::as_ref)),
+            ChunkTiles::Sparse(sparse) => {
+                Box::new((0..self.size.count()).map(move |index| sparse.get(index)))
+            }
+        }
     }
 
     /// Returns mutable iterator with all of the positions in the grid.
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Option<T>> {
-        self.tiles.iter_mut()
+    pub fn iter_mut(&mut self) -> Box<dyn Iterator<Item = Option<&mut T>> + '_> {
+        match &mut self.tiles {
+            ChunkTiles::Dense(tiles) => Box::new(tiles.iter_mut().map(Option::as_mut)),
+            ChunkTiles::Sparse(sparse) => {
+                // The trie can't be indexed directly, so collect the occupied entries (already
+                // in ascending index order) once and pad the gaps with `None` to match the
+                // dense iteration order.
+                let mut occupied = sparse.iter_entries_mut().collect::<Vec<_>>().into_iter();
+                let mut next = occupied.next();
+                Box::new((0..self.size.count()).map(move |index| match next {
+                    Some((occupied_index, _)) if occupied_index == index => {
+                        let (_, value) = next.take().unwrap();
+                        next = occupied.next();
+                        Some(value)
+                    }
+                    _ => None,
+                }))
+            }
+        }
+    }
+
+    /// Returns an iterator over only the occupied tiles, paired with their reconstructed
+    /// `TilePos`.
+    pub fn iter_pos(&self) -> Box<dyn Iterator<Item = (TilePos, &T)> + '_> {
+        let size = self.size;
+        match &self.tiles {
+            ChunkTiles::Dense(tiles) => Box::new(tiles.iter().enumerate().filter_map(
+                move |(index, tile)| tile.as_ref().map(|tile| (pos_from_index(index, &size), tile)),
+            )),
+            ChunkTiles::Sparse(sparse) => Box::new(
+                sparse
+                    .iter()
+                    .map(move |(index, tile)| (pos_from_index(index, &size), tile)),
+            ),
+        }
+    }
+
+    /// Returns a mutable iterator over only the occupied tiles, paired with their reconstructed
+    /// `TilePos`.
+    pub fn iter_pos_mut(&mut self) -> Box<dyn Iterator<Item = (TilePos, &mut T)> + '_> {
+        let size = self.size;
+        match &mut self.tiles {
+            ChunkTiles::Dense(tiles) => Box::new(tiles.iter_mut().enumerate().filter_map(
+                move |(index, tile)| tile.as_mut().map(|tile| (pos_from_index(index, &size), tile)),
+            )),
+            ChunkTiles::Sparse(sparse) => Box::new(
+                sparse
+                    .iter_entries_mut()
+                    .map(move |(index, tile)| (pos_from_index(index, &size), tile)),
+            ),
+        }
+    }
+
+    /// Computes a one-pass summary of how many tiles in this chunk are occupied vs vacant.
+    pub fn report(&self) -> ChunkStorageReport {
+        let capacity = self.size.count();
+        let occupied = match &self.tiles {
+            ChunkTiles::Dense(tiles) => tiles.iter().filter(|tile| tile.is_some()).count(),
+            ChunkTiles::Sparse(sparse) => sparse.len,
+        };
+        ChunkStorageReport {
+            occupied,
+            vacant: capacity - occupied,
+            capacity,
+        }
+    }
+
+    /// Returns the fraction of tiles in this chunk that are occupied, in `[0.0, 1.0]`.
+    ///
+    /// Useful for deciding whether a chunk would be better served by the dense or sparse
+    /// backend; see [`ChunkStorage::empty`] and [`ChunkStorage::sparse`]. Returns `0.0` for a
+    /// zero-sized (e.g. default) chunk rather than dividing by zero.
+    pub fn density(&self) -> f32 {
+        let report = self.report();
+        if report.capacity == 0 {
+            return 0.0;
+        }
+        report.occupied as f32 / report.capacity as f32
     }
 
     /// Removes any stored `T` at the given tile position, leaving `None` in its place and
@@ -135,7 +571,17 @@ impl<T> ChunkStorage<T> {
     ///
     /// Panics if the given `tile_pos` doesn't lie within the extents of the underlying tile map.
     pub fn remove(&mut self, tile_pos: &TilePos) -> Option<T> {
-        self.tiles[tile_pos.to_index(&self.size)].take()
+        let index = tile_pos.to_index(&self.size);
+        match &mut self.tiles {
+            ChunkTiles::Dense(tiles) => tiles[index].take(),
+            ChunkTiles::Sparse(sparse) => {
+                assert!(
+                    tile_pos.within_map_bounds(&self.size),
+                    "the given tile_pos lies outside the extents of the tile map"
+                );
+                sparse.remove(index)
+            }
+        }
     }
 
     /// Remove any stored `T` at the given tile position, leaving `None` in its place and
@@ -143,7 +589,14 @@ impl<T> ChunkStorage<T> {
     ///
     /// Checks that the given `tile_pos` lies within the extents of the underlying map.
     pub fn try_remove(&mut self, tile_pos: &TilePos) -> Option<T> {
-        self.tiles.get_mut(tile_pos.to_index(&self.size))?.take()
+        if !tile_pos.within_map_bounds(&self.size) {
+            return None;
+        }
+        let index = tile_pos.to_index(&self.size);
+        match &mut self.tiles {
+            ChunkTiles::Dense(tiles) => tiles.get_mut(index)?.take(),
+            ChunkTiles::Sparse(sparse) => sparse.remove(index),
+        }
     }
 
     /// Removes all stored `T`s, leaving `None` in their place and
@@ -160,7 +613,645 @@ impl<T> ChunkStorage<T> {
     /// }
     /// # }
     /// ```
-    pub fn drain(&mut self) -> impl Iterator<Item = T> {
-        self.tiles.iter_mut().filter_map(|opt| opt.take())
+    pub fn drain(&mut self) -> Box<dyn Iterator<Item = T> + '_> {
+        match &mut self.tiles {
+            ChunkTiles::Dense(tiles) => Box::new(tiles.iter_mut().filter_map(|opt| opt.take())),
+            ChunkTiles::Sparse(sparse) => Box::new(sparse.drain()),
+        }
+    }
+
+    /// Gets the given tile position's corresponding entry for in-place upsert.
+    ///
+    /// Panics if the given `tile_pos` doesn't lie within the extents of the underlying tile map.
+    pub fn entry(&mut self, tile_pos: &TilePos) -> Entry<'_, T> {
+        assert!(
+            tile_pos.within_map_bounds(&self.size),
+            "the given tile_pos lies outside the extents of the tile map"
+        );
+        let index = tile_pos.to_index(&self.size);
+        self.entry_at(index)
+    }
+
+    /// Gets the given tile position's corresponding entry for in-place upsert, if the tile
+    /// position lies within the underlying tile map's extents.
+    pub fn try_entry(&mut self, tile_pos: &TilePos) -> Result<Entry<'_, T>, TileError> {
+        if tile_pos.within_map_bounds(&self.size) {
+            let index = tile_pos.to_index(&self.size);
+            Ok(self.entry_at(index))
+        } else {
+            Err(TileError::OutOfBounds {
+                size: self.size,
+                target: *tile_pos,
+            })
+        }
+    }
+
+    fn entry_at(&mut self, index: usize) -> Entry<'_, T> {
+        match &mut self.tiles {
+            ChunkTiles::Dense(tiles) => {
+                if tiles[index].is_some() {
+                    Entry::Occupied(OccupiedEntry {
+                        value: tiles[index].as_mut().unwrap(),
+                    })
+                } else {
+                    Entry::Vacant(VacantEntry {
+                        slot: VacantSlot::Dense { tiles, index },
+                    })
+                }
+            }
+            ChunkTiles::Sparse(sparse) => {
+                if sparse.get(index).is_some() {
+                    Entry::Occupied(OccupiedEntry {
+                        value: sparse.get_mut(index).unwrap(),
+                    })
+                } else {
+                    Entry::Vacant(VacantEntry {
+                        slot: VacantSlot::Sparse { sparse, index },
+                    })
+                }
+            }
+        }
+    }
+}
+
+/// A view into a single tile position of a [`ChunkStorage`], which may either be occupied or
+/// vacant, obtained via [`ChunkStorage::entry`].
+pub enum Entry<'a, T> {
+    Occupied(OccupiedEntry<'a, T>),
+    Vacant(VacantEntry<'a, T>),
+}
+
+impl<'a, T> Entry<'a, T> {
+    /// Ensures a value is in the entry by inserting `default` if it is vacant, then returns a
+    /// mutable reference to the value.
+    pub fn or_insert(self, default: T) -> &'a mut T {
+        self.or_insert_with(|| default)
+    }
+
+    /// Ensures a value is in the entry by inserting the result of `default` if it is vacant,
+    /// then returns a mutable reference to the value.
+    pub fn or_insert_with(self, default: impl FnOnce() -> T) -> &'a mut T {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any `or_insert*` call.
+    pub fn and_modify(self, f: impl FnOnce(&mut T)) -> Self {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, T: Default> Entry<'a, T> {
+    /// Ensures a value is in the entry by inserting the default value if it is vacant, then
+    /// returns a mutable reference to the value.
+    pub fn or_default(self) -> &'a mut T {
+        self.or_insert_with(T::default)
+    }
+}
+
+/// A view into an occupied tile position of a [`ChunkStorage`].
+pub struct OccupiedEntry<'a, T> {
+    value: &'a mut T,
+}
+
+impl<'a, T> OccupiedEntry<'a, T> {
+    /// Gets a reference to the value in the entry.
+    pub fn get(&self) -> &T {
+        self.value
+    }
+
+    /// Gets a mutable reference to the value in the entry.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value
+    }
+
+    /// Converts the entry into a mutable reference to the value, tied to the storage's
+    /// lifetime rather than the entry's.
+    pub fn into_mut(self) -> &'a mut T {
+        self.value
+    }
+}
+
+/// Where a [`VacantEntry`] should write its value once [`VacantEntry::insert`] is called.
+enum VacantSlot<'a, T> {
+    Dense { tiles: &'a mut Vec<Option<T>>, index: usize },
+    Sparse { sparse: &'a mut SparseStorage<T>, index: usize },
+}
+
+/// A view into a vacant tile position of a [`ChunkStorage`].
+pub struct VacantEntry<'a, T> {
+    slot: VacantSlot<'a, T>,
+}
+
+impl<'a, T> VacantEntry<'a, T> {
+    /// Sets the value of the entry, returning a mutable reference to it.
+    pub fn insert(self, value: T) -> &'a mut T {
+        match self.slot {
+            VacantSlot::Dense { tiles, index } => {
+                tiles[index] = Some(value);
+                tiles[index].as_mut().unwrap()
+            }
+            VacantSlot::Sparse { sparse, index } => sparse.insert_mut(index, value),
+        }
+    }
+}
+
+impl<T> ChunkStorage<T> {
+    /// Checks that the rectangle spanned by `min` and `max` (inclusive) is non-inverted and lies
+    /// entirely within `size`, returning the offending corner as `TileError::OutOfBounds` if not.
+    fn check_region(min: TilePos, max: TilePos, size: &TilemapSize) -> Result<(), TileError> {
+        if !min.within_map_bounds(size) {
+            return Err(TileError::OutOfBounds {
+                size: *size,
+                target: min,
+            });
+        }
+        if !max.within_map_bounds(size) || max.x < min.x || max.y < min.y {
+            return Err(TileError::OutOfBounds {
+                size: *size,
+                target: max,
+            });
+        }
+        Ok(())
+    }
+
+    /// Fills the rectangular region spanned by `min` and `max` (inclusive), calling `f` with
+    /// each position in the region to produce its new value.
+    ///
+    /// Returns `TileError::OutOfBounds` naming the offending corner if any part of the region
+    /// falls outside `self.size`, without modifying any tile. The rectangle is validated once up
+    /// front; on the Dense backend each row is then written through a single contiguous slice
+    /// rather than through a `to_index`/bounds-check per tile.
+    pub fn fill_region(
+        &mut self,
+        min: TilePos,
+        max: TilePos,
+        mut f: impl FnMut(TilePos) -> T,
+    ) -> Result<(), TileError> {
+        Self::check_region(min, max, &self.size)?;
+        let size = self.size;
+        match &mut self.tiles {
+            ChunkTiles::Dense(tiles) => {
+                for y in min.y..=max.y {
+                    let row = y as usize * size.x as usize;
+                    let row_slice = &mut tiles[row + min.x as usize..=row + max.x as usize];
+                    for (x, slot) in (min.x..=max.x).zip(row_slice) {
+                        *slot = Some(f(TilePos { x, y }));
+                    }
+                }
+            }
+            ChunkTiles::Sparse(sparse) => {
+                for y in min.y..=max.y {
+                    for x in min.x..=max.x {
+                        let pos = TilePos { x, y };
+                        sparse.set(pos.to_index(&size), f(pos));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes every tile in the rectangular region spanned by `min` and `max` (inclusive),
+    /// returning the removed values in row-major order.
+    ///
+    /// Returns `TileError::OutOfBounds` naming the offending corner if any part of the region
+    /// falls outside `self.size`, without modifying any tile. The rectangle is validated once up
+    /// front; on the Dense backend each row is then cleared through a single contiguous slice
+    /// rather than through a `to_index`/bounds-check per tile.
+    pub fn clear_region(
+        &mut self,
+        min: TilePos,
+        max: TilePos,
+    ) -> Result<impl Iterator<Item = T>, TileError> {
+        Self::check_region(min, max, &self.size)?;
+        let size = self.size;
+        let mut removed = Vec::new();
+        match &mut self.tiles {
+            ChunkTiles::Dense(tiles) => {
+                for y in min.y..=max.y {
+                    let row = y as usize * size.x as usize;
+                    let row_slice = &mut tiles[row + min.x as usize..=row + max.x as usize];
+                    removed.extend(row_slice.iter_mut().filter_map(|slot| slot.take()));
+                }
+            }
+            ChunkTiles::Sparse(sparse) => {
+                for y in min.y..=max.y {
+                    for x in min.x..=max.x {
+                        let index = TilePos { x, y }.to_index(&size);
+                        removed.extend(sparse.remove(index));
+                    }
+                }
+            }
+        }
+        Ok(removed.into_iter())
+    }
+
+    /// Copies a `size`-shaped region starting at `src_min` in `src` to `dst_min` in `self`.
+    ///
+    /// Returns `TileError::OutOfBounds` naming the offending corner if either the source or
+    /// destination region falls outside its map's extents, without modifying any tile. The
+    /// rectangles are validated once up front; when both storages are Dense-backed, each row is
+    /// then copied via a single contiguous slice clone instead of a per-tile `to_index`/bounds
+    /// check on both ends.
+    pub fn copy_region_from(
+        &mut self,
+        src: &ChunkStorage<T>,
+        src_min: TilePos,
+        dst_min: TilePos,
+        size: TilemapSize,
+    ) -> Result<(), TileError>
+    where
+        T: Clone,
+    {
+        let src_max = TilePos {
+            x: src_min.x + size.x.saturating_sub(1),
+            y: src_min.y + size.y.saturating_sub(1),
+        };
+        let dst_max = TilePos {
+            x: dst_min.x + size.x.saturating_sub(1),
+            y: dst_min.y + size.y.saturating_sub(1),
+        };
+        Self::check_region(src_min, src_max, &src.size)?;
+        Self::check_region(dst_min, dst_max, &self.size)?;
+
+        if let (ChunkTiles::Dense(dst_tiles), ChunkTiles::Dense(src_tiles)) =
+            (&mut self.tiles, &src.tiles)
+        {
+            let src_width = src.size.x as usize;
+            let dst_width = self.size.x as usize;
+            let width = size.x as usize;
+            for y in 0..size.y as usize {
+                let src_row = (src_min.y as usize + y) * src_width + src_min.x as usize;
+                let dst_row = (dst_min.y as usize + y) * dst_width + dst_min.x as usize;
+                dst_tiles[dst_row..dst_row + width]
+                    .clone_from_slice(&src_tiles[src_row..src_row + width]);
+            }
+            return Ok(());
+        }
+
+        let src_size = src.size;
+        let dst_size = self.size;
+        for y in 0..size.y {
+            for x in 0..size.x {
+                let src_index = TilePos {
+                    x: src_min.x + x,
+                    y: src_min.y + y,
+                }
+                .to_index(&src_size);
+                let dst_index = TilePos {
+                    x: dst_min.x + x,
+                    y: dst_min.y + y,
+                }
+                .to_index(&dst_size);
+                let tile = match &src.tiles {
+                    ChunkTiles::Dense(tiles) => tiles[src_index].clone(),
+                    ChunkTiles::Sparse(sparse) => sparse.get(src_index).cloned(),
+                };
+                match (&mut self.tiles, tile) {
+                    (ChunkTiles::Dense(tiles), tile) => tiles[dst_index] = tile,
+                    (ChunkTiles::Sparse(sparse), Some(tile)) => {
+                        sparse.set(dst_index, tile);
+                    }
+                    (ChunkTiles::Sparse(sparse), None) => {
+                        sparse.remove(dst_index);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: Send + Sync> ChunkStorage<T> {
+    /// Returns a Rayon parallel iterator over the occupied tiles.
+    ///
+    /// Bulk tile operations (recomputing colors, cellular-automata passes, visibility) are
+    /// embarrassingly parallel; this lets them run across all of Rayon's thread pool instead of
+    /// single-threaded through [`ChunkStorage::iter`].
+    ///
+    /// Built on [`ChunkStorage::iter_pos`] rather than [`ChunkStorage::iter`] so that, on the
+    /// Sparse backend, collecting runs in `O(occupied)` instead of walking the trie once per
+    /// index in `0..size.count()`.
+    pub fn par_iter(&self) -> impl ParallelIterator<Item = &T> {
+        self.iter_pos()
+            .map(|(_, tile)| tile)
+            .collect::<Vec<_>>()
+            .into_par_iter()
+    }
+
+    /// Returns a mutable Rayon parallel iterator over the occupied tiles.
+    ///
+    /// See [`ChunkStorage::par_iter`] for why this is built on [`ChunkStorage::iter_pos_mut`]
+    /// rather than [`ChunkStorage::iter_mut`].
+    pub fn par_iter_mut(&mut self) -> impl ParallelIterator<Item = &mut T> {
+        self.iter_pos_mut()
+            .map(|(_, tile)| tile)
+            .collect::<Vec<_>>()
+            .into_par_iter()
+    }
+
+    /// Returns a Rayon parallel iterator over the occupied tiles, paired with their
+    /// reconstructed `TilePos`.
+    pub fn par_iter_pos(&self) -> impl ParallelIterator<Item = (TilePos, &T)> {
+        self.iter_pos().collect::<Vec<_>>().into_par_iter()
+    }
+
+    /// Returns a mutable Rayon parallel iterator over the occupied tiles, paired with their
+    /// reconstructed `TilePos`.
+    pub fn par_iter_pos_mut(&mut self) -> impl ParallelIterator<Item = (TilePos, &mut T)> {
+        self.iter_pos_mut().collect::<Vec<_>>().into_par_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small_size() -> TilemapSize {
+        TilemapSize { x: 4, y: 4 }
+    }
+
+    #[test]
+    fn density_of_zero_sized_chunk_is_zero_not_nan() {
+        let storage: ChunkStorage<i32> = ChunkStorage::default();
+        assert_eq!(storage.density(), 0.0);
+    }
+
+    #[test]
+    fn sparse_set_get_remove_round_trip() {
+        let mut storage: ChunkStorage<i32> = ChunkStorage::sparse(small_size());
+        let pos = TilePos { x: 2, y: 3 };
+
+        assert_eq!(storage.get(&pos), None);
+        assert_eq!(storage.set(&pos, 42), None);
+        assert_eq!(storage.get(&pos), Some(&42));
+        assert_eq!(storage.set(&pos, 7), Some(42));
+        assert_eq!(storage.remove(&pos), Some(7));
+        assert_eq!(storage.get(&pos), None);
+    }
+
+    #[test]
+    fn sparse_remove_collapses_empty_subtrees() {
+        let mut storage: ChunkStorage<i32> = ChunkStorage::sparse(small_size());
+        let pos = TilePos { x: 1, y: 1 };
+
+        storage.set(&pos, 1);
+        assert_eq!(storage.report().occupied, 1);
+
+        storage.remove(&pos);
+        let ChunkTiles::Sparse(sparse) = &storage.tiles else {
+            panic!("expected sparse backend");
+        };
+        assert!(sparse.root.is_none(), "removing the only tile should collapse the trie back to an empty root");
+        assert_eq!(sparse.len, 0);
+    }
+
+    #[test]
+    fn entry_dense_vacant_then_occupied() {
+        let mut storage: ChunkStorage<i32> = ChunkStorage::empty(small_size());
+        let pos = TilePos { x: 0, y: 0 };
+
+        assert_eq!(*storage.entry(&pos).or_insert(5), 5);
+        storage.entry(&pos).and_modify(|v| *v += 1);
+        assert_eq!(storage.get(&pos), Some(&6));
+    }
+
+    #[test]
+    fn entry_sparse_vacant_then_occupied() {
+        let mut storage: ChunkStorage<i32> = ChunkStorage::sparse(small_size());
+        let pos = TilePos { x: 3, y: 2 };
+
+        assert_eq!(*storage.entry(&pos).or_insert(5), 5);
+        storage.entry(&pos).and_modify(|v| *v += 1);
+        assert_eq!(storage.get(&pos), Some(&6));
+    }
+
+    #[test]
+    #[should_panic]
+    fn entry_sparse_out_of_row_bounds_panics() {
+        let mut storage: ChunkStorage<i32> = ChunkStorage::sparse(TilemapSize { x: 10, y: 10 });
+        // index 15 < size.count() == 100, but x == 15 is outside the 10-wide row.
+        storage.entry(&TilePos { x: 15, y: 0 });
+    }
+
+    #[test]
+    fn sparse_iter_and_iter_mut_match_dense_ordering() {
+        let size = small_size();
+        let mut storage: ChunkStorage<i32> = ChunkStorage::sparse(size);
+        storage.set(&TilePos { x: 0, y: 0 }, 1);
+        storage.set(&TilePos { x: 3, y: 0 }, 2);
+        storage.set(&TilePos { x: 2, y: 2 }, 3);
+
+        let expected: Vec<Option<i32>> = (0..size.count())
+            .map(|index| match index {
+                0 => Some(1),
+                3 => Some(2),
+                10 => Some(3),
+                _ => None,
+            })
+            .collect();
+
+        let seen: Vec<Option<i32>> = storage.iter().map(|tile| tile.copied()).collect();
+        assert_eq!(seen, expected);
+
+        let seen_mut: Vec<Option<i32>> = storage.iter_mut().map(|tile| tile.map(|v| *v)).collect();
+        assert_eq!(seen_mut, expected);
+    }
+
+    #[test]
+    fn reflect_does_not_expose_tile_contents() {
+        use bevy::reflect::Struct;
+
+        let mut storage: ChunkStorage<i32> = ChunkStorage::empty(small_size());
+        storage.set(&TilePos { x: 0, y: 0 }, 1);
+
+        // Only `size` is reflected; `tiles` is `#[reflect(ignore)]` on both backends.
+        assert_eq!(storage.field_len(), 1);
+        assert_eq!(storage.name_at(0), Some("size"));
+    }
+
+    #[test]
+    fn region_fill_clear_and_copy() {
+        let size = small_size();
+        let mut storage: ChunkStorage<i32> = ChunkStorage::empty(size);
+
+        storage
+            .fill_region(TilePos { x: 1, y: 1 }, TilePos { x: 2, y: 2 }, |pos| {
+                (pos.x + pos.y) as i32
+            })
+            .unwrap();
+        assert_eq!(storage.get(&TilePos { x: 1, y: 1 }), Some(&2));
+        assert_eq!(storage.get(&TilePos { x: 2, y: 2 }), Some(&4));
+        assert_eq!(storage.get(&TilePos { x: 0, y: 0 }), None);
+
+        let mut dst: ChunkStorage<i32> = ChunkStorage::empty(size);
+        dst.copy_region_from(
+            &storage,
+            TilePos { x: 1, y: 1 },
+            TilePos { x: 0, y: 0 },
+            TilemapSize { x: 2, y: 2 },
+        )
+        .unwrap();
+        assert_eq!(dst.get(&TilePos { x: 0, y: 0 }), Some(&2));
+        assert_eq!(dst.get(&TilePos { x: 1, y: 1 }), Some(&4));
+
+        let cleared: Vec<i32> = storage
+            .clear_region(TilePos { x: 1, y: 1 }, TilePos { x: 2, y: 2 })
+            .unwrap()
+            .collect();
+        assert_eq!(cleared.len(), 4);
+        assert_eq!(storage.get(&TilePos { x: 1, y: 1 }), None);
+        assert_eq!(storage.get(&TilePos { x: 2, y: 2 }), None);
+    }
+
+    #[test]
+    fn region_inverted_rectangle_errors_instead_of_panicking() {
+        let size = small_size();
+        let mut storage: ChunkStorage<i32> = ChunkStorage::empty(size);
+
+        let result = storage.fill_region(TilePos { x: 3, y: 0 }, TilePos { x: 1, y: 0 }, |_| 1);
+        assert!(matches!(result, Err(TileError::OutOfBounds { .. })));
+        assert_eq!(storage.report().occupied, 0);
+    }
+
+    #[test]
+    fn region_out_of_bounds_rectangle_errors_without_mutating() {
+        let size = small_size();
+        let mut storage: ChunkStorage<i32> = ChunkStorage::empty(size);
+
+        let result = storage.fill_region(TilePos { x: 0, y: 0 }, TilePos { x: 4, y: 0 }, |_| 1);
+        assert!(matches!(
+            result,
+            Err(TileError::OutOfBounds {
+                target: TilePos { x: 4, y: 0 },
+                ..
+            })
+        ));
+        assert_eq!(storage.report().occupied, 0);
+    }
+
+    #[test]
+    fn region_fill_clear_and_copy_on_sparse_backend() {
+        let size = small_size();
+        let mut storage: ChunkStorage<i32> = ChunkStorage::sparse(size);
+
+        storage
+            .fill_region(TilePos { x: 1, y: 1 }, TilePos { x: 2, y: 2 }, |pos| {
+                (pos.x + pos.y) as i32
+            })
+            .unwrap();
+        assert_eq!(storage.get(&TilePos { x: 1, y: 1 }), Some(&2));
+        assert_eq!(storage.get(&TilePos { x: 2, y: 2 }), Some(&4));
+        assert_eq!(storage.get(&TilePos { x: 0, y: 0 }), None);
+
+        let mut dst: ChunkStorage<i32> = ChunkStorage::sparse(size);
+        dst.copy_region_from(
+            &storage,
+            TilePos { x: 1, y: 1 },
+            TilePos { x: 0, y: 0 },
+            TilemapSize { x: 2, y: 2 },
+        )
+        .unwrap();
+        assert_eq!(dst.get(&TilePos { x: 0, y: 0 }), Some(&2));
+        assert_eq!(dst.get(&TilePos { x: 1, y: 1 }), Some(&4));
+
+        let cleared: Vec<i32> = storage
+            .clear_region(TilePos { x: 1, y: 1 }, TilePos { x: 2, y: 2 })
+            .unwrap()
+            .collect();
+        assert_eq!(cleared.len(), 4);
+        assert_eq!(storage.get(&TilePos { x: 1, y: 1 }), None);
+        assert_eq!(storage.get(&TilePos { x: 2, y: 2 }), None);
+        assert_eq!(storage.report().occupied, 0);
+    }
+
+    #[test]
+    fn copy_region_from_mixes_dense_and_sparse_backends() {
+        let size = small_size();
+        let region = TilemapSize { x: 2, y: 2 };
+
+        let mut sparse_src: ChunkStorage<i32> = ChunkStorage::sparse(size);
+        sparse_src
+            .fill_region(TilePos { x: 1, y: 1 }, TilePos { x: 2, y: 2 }, |pos| {
+                (pos.x + pos.y) as i32
+            })
+            .unwrap();
+
+        let mut dense_dst: ChunkStorage<i32> = ChunkStorage::empty(size);
+        dense_dst
+            .copy_region_from(&sparse_src, TilePos { x: 1, y: 1 }, TilePos { x: 0, y: 0 }, region)
+            .unwrap();
+        assert_eq!(dense_dst.get(&TilePos { x: 0, y: 0 }), Some(&2));
+        assert_eq!(dense_dst.get(&TilePos { x: 1, y: 1 }), Some(&4));
+
+        let mut dense_src: ChunkStorage<i32> = ChunkStorage::empty(size);
+        dense_src
+            .fill_region(TilePos { x: 1, y: 1 }, TilePos { x: 2, y: 2 }, |pos| {
+                (pos.x + pos.y) as i32
+            })
+            .unwrap();
+
+        let mut sparse_dst: ChunkStorage<i32> = ChunkStorage::sparse(size);
+        sparse_dst
+            .copy_region_from(&dense_src, TilePos { x: 1, y: 1 }, TilePos { x: 0, y: 0 }, region)
+            .unwrap();
+        assert_eq!(sparse_dst.get(&TilePos { x: 0, y: 0 }), Some(&2));
+        assert_eq!(sparse_dst.get(&TilePos { x: 1, y: 1 }), Some(&4));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_iter_matches_sequential_iter_pos_for_dense_and_sparse() {
+        let size = small_size();
+
+        let mut dense: ChunkStorage<i32> = ChunkStorage::empty(size);
+        let mut sparse: ChunkStorage<i32> = ChunkStorage::sparse(size);
+        for storage in [&mut dense, &mut sparse] {
+            storage.set(&TilePos { x: 0, y: 0 }, 1);
+            storage.set(&TilePos { x: 3, y: 3 }, 2);
+        }
+
+        for storage in [&dense, &sparse] {
+            let mut expected: Vec<i32> = storage.iter_pos().map(|(_, tile)| *tile).collect();
+            expected.sort();
+
+            let mut got: Vec<i32> = storage.par_iter().copied().collect();
+            got.sort();
+            assert_eq!(got, expected);
+
+            let mut got_pos: Vec<(u32, u32, i32)> = storage
+                .par_iter_pos()
+                .map(|(pos, tile)| (pos.x, pos.y, *tile))
+                .collect();
+            got_pos.sort();
+            let mut expected_pos: Vec<(u32, u32, i32)> = storage
+                .iter_pos()
+                .map(|(pos, tile)| (pos.x, pos.y, *tile))
+                .collect();
+            expected_pos.sort();
+            assert_eq!(got_pos, expected_pos);
+        }
+
+        for storage in [&mut dense, &mut sparse] {
+            storage.par_iter_mut().for_each(|tile| *tile *= 10);
+            let mut values: Vec<i32> = storage.iter_pos().map(|(_, tile)| *tile).collect();
+            values.sort();
+            assert_eq!(values, vec![10, 20]);
+
+            storage.par_iter_pos_mut().for_each(|(_, tile)| *tile += 1);
+            let mut values: Vec<i32> = storage.iter_pos().map(|(_, tile)| *tile).collect();
+            values.sort();
+            assert_eq!(values, vec![11, 21]);
+        }
     }
 }